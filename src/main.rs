@@ -4,6 +4,8 @@ struct Body {
     mass: f32,
     inertia: f32,
     shape: FiniteShape,
+    // coefficient of restitution: 0 is perfectly inelastic, 1 is perfectly elastic
+    restitution: f32,
 
     pos: Vec2,
     vel: Vec2,
@@ -14,15 +16,36 @@ struct Body {
     torque: f32,
 }
 
+// Baumgarte stabilization constants for positional correction.
+// SLOP allows a small overlap on resting contacts so they don't jitter.
+// BETA is the fraction of the remaining penetration corrected per step.
+// Unlike `restitution`, these aren't per-`Body` fields: there's only one
+// scene's worth of geometry here and nothing yet needs per-body tuning; if
+// that changes, move BETA onto `Body` the same way `restitution` is.
+const SLOP: f32 = 0.01;
+const BETA: f32 = 0.2;
+
 struct Penetration {
     pos: Vec2,
     normal: Vec2,
     depth: f32,
 }
 
+// A collision found by sweeping a body's motion over the frame instead of
+// testing its post-integration position, so fast bodies can't tunnel through
+// thin geometry. `t` is the fraction of the motion (in [0, 1]) at which the
+// body first touches, and `pos` is the body's center clamped to that time.
+struct Swept {
+    t: f32,
+    pos: Vec2,
+    normal: Vec2,
+    depth: f32,
+}
+
 enum CollisionResult {
     NoCollision,
     Penetration(Penetration),
+    Swept(Swept),
     FullOverlap,
 }
 
@@ -62,12 +85,255 @@ fn circle_line_collision(c: &Circle, l: &Line) -> CollisionResult {
     }
 }
 
+// Sweep a circle of radius `r` moving from `p0` to `p1` against `l`, finding
+// the earliest time of impact. Bodies that already overlap the line at `p0`
+// are left to the discrete `circle_line_collision` resting-contact check.
+fn circle_line_sweep(p0: Vec2, p1: Vec2, r: f32, l: &Line) -> CollisionResult {
+    let denom = (l.a * l.a + l.b * l.b).sqrt();
+    let dist = |p: Vec2| (l.a * p.x + l.b * p.y + l.c) / denom;
+
+    let d0 = dist(p0);
+    let d1 = dist(p1);
+
+    // `d0 == d1` means no motion along the line's normal (e.g. sliding
+    // straight along it) — there's no crossing to solve for, and dividing
+    // through would produce a NaN `t`.
+    if d0 < r || (d0 - r) * (d1 - r) > 0. || (d0 - d1).abs() < f32::EPSILON {
+        return CollisionResult::NoCollision;
+    }
+
+    let t = (d0 - r) / (d0 - d1);
+    let pos = p0 + t * (p1 - p0);
+    let normal = Vec2::new(l.a, l.b).normalize();
+    let depth = r - d1;
+    CollisionResult::Swept(Swept {
+        t,
+        pos,
+        normal,
+        depth,
+    })
+}
+
+// Sweep a circle of radius `r1` moving from `p0` to `p1` against `c2`,
+// solving the quadratic for the smallest root in [0, 1]. Bodies that already
+// overlap `c2` at `p0` are left to the discrete `circle_circle_collision`
+// resting-contact check.
+fn circle_circle_sweep(p0: Vec2, p1: Vec2, r1: f32, c2: &Circle) -> CollisionResult {
+    let v = p1 - p0;
+    let rel = p0 - c2.pos;
+    let r = r1 + c2.r;
+
+    let a = v.dot(v);
+    if a < f32::EPSILON || rel.dot(rel) <= r * r {
+        return CollisionResult::NoCollision;
+    }
+
+    let b = 2. * rel.dot(v);
+    let c = rel.dot(rel) - r * r;
+    let disc = b * b - 4. * a * c;
+    if disc < 0. {
+        return CollisionResult::NoCollision;
+    }
+
+    let t = (-b - disc.sqrt()) / (2. * a);
+    if !(0. ..=1.).contains(&t) {
+        return CollisionResult::NoCollision;
+    }
+
+    let pos = p0 + t * v;
+    let normal = (pos - c2.pos).normalize();
+    CollisionResult::Swept(Swept {
+        t,
+        pos,
+        normal,
+        depth: 0.,
+    })
+}
+
+fn centroid(verts: &[Vec2]) -> Vec2 {
+    verts.iter().fold(Vec2::ZERO, |acc, v| acc + *v) / verts.len() as f32
+}
+
+// World-space positions of a polygon's local-space vertices, as seen on a
+// `Body` with the given `pos` and `angle`.
+fn world_verts(verts: &[Vec2], pos: Vec2, angle: f32) -> Vec<Vec2> {
+    let rot = Mat2::from_angle(angle);
+    verts.iter().map(|v| pos + rot * *v).collect()
+}
+
+// The outward normal of every edge of a convex polygon, used as SAT
+// candidate axes.
+fn edge_normals(verts: &[Vec2]) -> Vec<Vec2> {
+    let n = verts.len();
+    (0..n)
+        .map(|i| {
+            let edge = verts[(i + 1) % n] - verts[i];
+            Vec2::new(edge.y, -edge.x).normalize()
+        })
+        .collect()
+}
+
+fn project(verts: &[Vec2], axis: Vec2) -> (f32, f32) {
+    verts.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+        let p = v.dot(axis);
+        (min.min(p), max.max(p))
+    })
+}
+
+// Separating Axis Theorem: test world-space convex polygons `verts1` and
+// `verts2` against every edge normal of both, returning the axis and depth
+// of least overlap, or `None` if any axis separates them.
+fn sat_overlap(verts1: &[Vec2], verts2: &[Vec2]) -> Option<(Vec2, f32)> {
+    let mut best_axis = Vec2::ZERO;
+    let mut best_overlap = f32::INFINITY;
+
+    for axis in edge_normals(verts1).into_iter().chain(edge_normals(verts2)) {
+        let (min1, max1) = project(verts1, axis);
+        let (min2, max2) = project(verts2, axis);
+
+        let overlap = max1.min(max2) - min1.max(min2);
+        if overlap <= 0. {
+            return None;
+        }
+        if overlap < best_overlap {
+            best_overlap = overlap;
+            best_axis = axis;
+        }
+    }
+
+    Some((best_axis, best_overlap))
+}
+
+fn polygon_polygon_collision(verts1: &[Vec2], verts2: &[Vec2]) -> CollisionResult {
+    let Some((mut normal, depth)) = sat_overlap(verts1, verts2) else {
+        return CollisionResult::NoCollision;
+    };
+
+    // Orient the normal to point from verts2 (geometry) towards verts1 (the
+    // incident, colliding body).
+    if (centroid(verts1) - centroid(verts2)).dot(normal) < 0. {
+        normal = -normal;
+    }
+
+    // Contact point: the deepest penetrating vertex of the incident polygon.
+    let pos = verts1
+        .iter()
+        .copied()
+        .min_by(|a, b| a.dot(normal).partial_cmp(&b.dot(normal)).unwrap())
+        .unwrap();
+
+    CollisionResult::Penetration(Penetration { pos, normal, depth })
+}
+
+fn polygon_line_collision(verts: &[Vec2], l: &Line) -> CollisionResult {
+    let normal = Vec2::new(l.a, l.b).normalize();
+    let denom = (l.a * l.a + l.b * l.b).sqrt();
+    let dist = |p: Vec2| (l.a * p.x + l.b * p.y + l.c) / denom;
+
+    let (min_d, max_d) = verts
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+            let d = dist(*v);
+            (min.min(d), max.max(d))
+        });
+
+    if min_d > 0. {
+        CollisionResult::NoCollision
+    } else if max_d < 0. {
+        CollisionResult::FullOverlap
+    } else {
+        let pos = verts
+            .iter()
+            .copied()
+            .min_by(|a, b| dist(*a).partial_cmp(&dist(*b)).unwrap())
+            .unwrap();
+        CollisionResult::Penetration(Penetration {
+            pos,
+            normal,
+            depth: -min_d,
+        })
+    }
+}
+
+// SAT axes for circle-vs-polygon: every edge normal of the polygon, plus the
+// axis from the circle center to the polygon's nearest vertex.
+fn circle_polygon_overlap(verts: &[Vec2], c: &Circle) -> Option<(Vec2, f32)> {
+    let nearest = verts
+        .iter()
+        .copied()
+        .min_by(|a, b| {
+            (*a - c.pos)
+                .length_squared()
+                .partial_cmp(&(*b - c.pos).length_squared())
+                .unwrap()
+        })
+        .unwrap();
+    let vertex_axis = (c.pos - nearest).normalize();
+
+    let mut best_axis = Vec2::ZERO;
+    let mut best_overlap = f32::INFINITY;
+
+    for axis in edge_normals(verts).into_iter().chain([vertex_axis]) {
+        let (pmin, pmax) = project(verts, axis);
+        let cproj = c.pos.dot(axis);
+
+        let overlap = pmax.min(cproj + c.r) - pmin.max(cproj - c.r);
+        if overlap <= 0. {
+            return None;
+        }
+        if overlap < best_overlap {
+            best_overlap = overlap;
+            best_axis = axis;
+        }
+    }
+
+    Some((best_axis, best_overlap))
+}
+
+fn circle_polygon_collision(c: &Circle, verts: &[Vec2]) -> CollisionResult {
+    let Some((mut normal, depth)) = circle_polygon_overlap(verts, c) else {
+        return CollisionResult::NoCollision;
+    };
+
+    if (c.pos - centroid(verts)).dot(normal) < 0. {
+        normal = -normal;
+    }
+
+    let pos = c.pos - c.r * normal;
+    CollisionResult::Penetration(Penetration { pos, normal, depth })
+}
+
+fn polygon_circle_collision(verts: &[Vec2], c: &Circle) -> CollisionResult {
+    let Some((mut normal, depth)) = circle_polygon_overlap(verts, c) else {
+        return CollisionResult::NoCollision;
+    };
+
+    if (centroid(verts) - c.pos).dot(normal) < 0. {
+        normal = -normal;
+    }
+
+    let pos = verts
+        .iter()
+        .copied()
+        .min_by(|a, b| a.dot(normal).partial_cmp(&b.dot(normal)).unwrap())
+        .unwrap();
+    CollisionResult::Penetration(Penetration { pos, normal, depth })
+}
+
 impl Body {
-    fn new(mass: f32, inertia: f32, shape: FiniteShape, pos: Vec2, angle: f32) -> Body {
+    fn new(
+        mass: f32,
+        inertia: f32,
+        shape: FiniteShape,
+        restitution: f32,
+        pos: Vec2,
+        angle: f32,
+    ) -> Body {
         Body {
             mass,
             inertia,
             shape,
+            restitution,
             pos,
             vel: Vec2::ZERO,
             force: Vec2::ZERO,
@@ -77,10 +343,11 @@ impl Body {
         }
     }
 
-    fn step(&mut self, dt: f32) {
+    // Integrate forces/torques into velocity and angle. Position is advanced
+    // separately by `advance`, which sweeps the motion against geometry.
+    fn integrate(&mut self, dt: f32) {
         let acc = self.force / self.mass;
         self.vel += acc * dt;
-        self.pos += self.vel * dt;
         self.force = Vec2::ZERO;
 
         let alpha = self.torque / self.inertia;
@@ -89,31 +356,166 @@ impl Body {
         self.torque = 0.;
     }
 
-    fn collide(&mut self, geom: &Geometry) {
+    fn sweep(&self, p0: Vec2, p1: Vec2, geom: &Geometry) -> CollisionResult {
         match &self.shape {
-            FiniteShape::Circle(c1) => {
+            FiniteShape::Circle(c1) => match geom {
+                Geometry::Finite(FiniteShape::Circle(c2)) => circle_circle_sweep(p0, p1, c1.r, c2),
+                Geometry::Infinite(InfiniteShape::Line(l)) => circle_line_sweep(p0, p1, c1.r, l),
+                Geometry::Finite(FiniteShape::Polygon { .. }) => CollisionResult::NoCollision,
+            },
+            // Continuous collision isn't implemented for polygons yet; they
+            // fall back to the discrete per-frame `collide` check below.
+            FiniteShape::Polygon { .. } => CollisionResult::NoCollision,
+        }
+    }
+
+    // Advance the body's position from `self.pos` by `self.vel * dt`, but
+    // stop at the earliest point along the way where it touches geometry
+    // instead of overshooting through it, resolve the contact there, and
+    // spend the rest of the frame moving with the post-impact velocity. That
+    // remaining motion is not swept again, and only circle bodies against
+    // lines and circles are swept at all; polygons rely on `collide`.
+    // Returns the contact (geometry index, point, normal, depth, impulse)
+    // for the caller to turn into a `CollisionEvent`, same as `collide`.
+    fn advance(
+        &mut self,
+        geometry: &[Geometry],
+        dt: f32,
+    ) -> Option<(GeometryRef, Vec2, Vec2, f32, f32)> {
+        let p0 = self.pos;
+        let p1 = p0 + self.vel * dt;
+
+        let earliest = geometry
+            .iter()
+            .enumerate()
+            .filter_map(|(gi, g)| match self.sweep(p0, p1, g) {
+                CollisionResult::Swept(s) => Some((gi, s)),
+                _ => None,
+            })
+            .min_by(|(_, a), (_, b)| a.t.partial_cmp(&b.t).unwrap());
+
+        match earliest {
+            Some((
+                gi,
+                Swept {
+                    t,
+                    pos,
+                    normal,
+                    depth,
+                },
+            )) => {
+                // The sweep only reports the body's center at the time of
+                // impact; derive the actual contact point on its boundary
+                // for the rigid-body solve in `resolve`.
+                let r = match &self.shape {
+                    FiniteShape::Circle(c) => c.r,
+                    FiniteShape::Polygon { .. } => unreachable!("sweep() never hits a polygon"),
+                };
+                let contact = pos - r * normal;
+
+                self.pos = pos;
+                let impulse = self.resolve(contact, normal, depth);
+                self.pos += self.vel * (1. - t) * dt;
+
+                Some((gi, contact, normal, depth, impulse))
+            }
+            None => {
+                self.pos = p1;
+                None
+            }
+        }
+    }
+
+    // Positional correction plus a rigid-body impulse response with
+    // restitution, shared by the discrete and swept collision paths. `pos`
+    // is the contact point in world space, used as the lever arm for torque.
+    // Returns the normal impulse magnitude applied (0 if the body was
+    // already separating).
+    fn resolve(&mut self, pos: Vec2, normal: Vec2, depth: f32) -> f32 {
+        // Lever arm and contact velocity, taken from the actual contact
+        // geometry at the time of impact — before the positional correction
+        // below nudges `self.pos`, which would otherwise skew the torque.
+        let r = pos - self.pos;
+        let perp_r: Vec2 = (-r.y, r.x).into();
+        let contact_vel = self.vel + self.omega * perp_r;
+
+        // Positional correction: push the body out of the geometry so it
+        // doesn't sink in and drift, but leave a bit of slop so resting
+        // contacts don't jitter.
+        self.pos += (depth - SLOP).max(0.) * BETA * normal;
+
+        // Impulse-based resolution with restitution instead of a hard
+        // mirror reflection. Only apply it while the body is still
+        // approaching the contact; don't pull it back in once it's
+        // separating.
+        let closing_vel = contact_vel.dot(normal);
+        if closing_vel < 0. {
+            let cross_r_n = r.x * normal.y - r.y * normal.x;
+            let k = 1. / self.mass + cross_r_n * cross_r_n / self.inertia;
+            let j = -(1. + self.restitution) * closing_vel / k;
+
+            self.vel += j / self.mass * normal;
+            self.omega += cross_r_n * j / self.inertia;
+            j
+        } else {
+            0.
+        }
+    }
+
+    // Resolves a discrete collision against `geom`, if any, and reports the
+    // contact for the caller to turn into a `CollisionEvent`.
+    fn collide(&mut self, geom: &Geometry) -> Option<(Vec2, Vec2, f32, f32)> {
+        let res = match (&self.shape, geom) {
+            (FiniteShape::Circle(c1), Geometry::Finite(FiniteShape::Circle(c2))) => {
                 let c = Circle {
                     pos: self.pos,
                     r: c1.r,
                 };
-
-                let res = match geom {
-                    Geometry::Finite(FiniteShape::Circle(c2)) => circle_circle_collision(&c, c2),
-                    Geometry::Infinite(InfiniteShape::Line(l)) => circle_line_collision(&c, l),
+                circle_circle_collision(&c, c2)
+            }
+            (FiniteShape::Circle(c1), Geometry::Infinite(InfiniteShape::Line(l))) => {
+                let c = Circle {
+                    pos: self.pos,
+                    r: c1.r,
                 };
-
-                if let CollisionResult::Penetration(Penetration { normal, .. }) = res {
-                    // Just reflect the velocity along the penetration normal
-                    self.vel -= 2. * self.vel.dot(normal) * normal;
-                    //self.pos += depth * n;
-                }
+                circle_line_collision(&c, l)
             }
+            (FiniteShape::Circle(c1), Geometry::Finite(FiniteShape::Polygon { verts })) => {
+                let c = Circle {
+                    pos: self.pos,
+                    r: c1.r,
+                };
+                circle_polygon_collision(&c, verts)
+            }
+            (FiniteShape::Polygon { verts }, Geometry::Finite(FiniteShape::Circle(c2))) => {
+                let world = world_verts(verts, self.pos, self.angle);
+                polygon_circle_collision(&world, c2)
+            }
+            (FiniteShape::Polygon { verts }, Geometry::Infinite(InfiniteShape::Line(l))) => {
+                let world = world_verts(verts, self.pos, self.angle);
+                polygon_line_collision(&world, l)
+            }
+            (
+                FiniteShape::Polygon { verts },
+                Geometry::Finite(FiniteShape::Polygon { verts: verts2 }),
+            ) => {
+                let world = world_verts(verts, self.pos, self.angle);
+                polygon_polygon_collision(&world, verts2)
+            }
+        };
+
+        if let CollisionResult::Penetration(Penetration { pos, normal, depth }) = res {
+            let impulse = self.resolve(pos, normal, depth);
+            Some((pos, normal, depth, impulse))
+        } else {
+            None
         }
     }
 
     fn draw(&self) {
-        match self.shape {
+        match &self.shape {
             FiniteShape::Circle(Circle { r, .. }) => {
+                let r = *r;
                 draw_circle_lines(self.pos.x, self.pos.y, r, 1.0, WHITE);
 
                 let rot = Mat2::from_angle(self.angle);
@@ -122,10 +524,20 @@ impl Body {
                 draw_line_vec(self.pos + xdelta, self.pos - xdelta);
                 draw_line_vec(self.pos + ydelta, self.pos - ydelta);
             }
+            FiniteShape::Polygon { verts } => {
+                draw_polygon_lines(&world_verts(verts, self.pos, self.angle));
+            }
         }
     }
 }
 
+fn draw_polygon_lines(verts: &[Vec2]) {
+    let n = verts.len();
+    for i in 0..n {
+        draw_line_vec(verts[i], verts[(i + 1) % n]);
+    }
+}
+
 fn draw_line_vec(a: Vec2, b: Vec2) {
     draw_line(a.x, a.y, b.x, b.y, 1.0, WHITE);
 }
@@ -182,12 +594,40 @@ impl InfiniteShape {
 
 enum FiniteShape {
     Circle(Circle),
+    // Vertices of a convex hull, in local space when attached to a `Body`
+    // (rotated and translated by its `angle`/`pos`), or in world space when
+    // used directly as static `Geometry`.
+    Polygon { verts: Vec<Vec2> },
 }
 
 impl FiniteShape {
     fn draw(&self) {
         match self {
             FiniteShape::Circle(circle) => circle.draw(),
+            FiniteShape::Polygon { verts } => draw_polygon_lines(verts),
+        }
+    }
+
+    // Axis-aligned bounding box in world space, given the `pos`/`angle` of
+    // the `Body` (or `Vec2::ZERO`/`0.` for shapes already in world space, as
+    // used directly by static `Geometry`).
+    fn aabb(&self, pos: Vec2, angle: f32) -> (Vec2, Vec2) {
+        match self {
+            FiniteShape::Circle(c) => {
+                let center = pos + Mat2::from_angle(angle) * c.pos;
+                let r = Vec2::splat(c.r);
+                (center - r, center + r)
+            }
+            FiniteShape::Polygon { verts } => {
+                let world = world_verts(verts, pos, angle);
+                let min = world
+                    .iter()
+                    .fold(Vec2::splat(f32::INFINITY), |acc, v| acc.min(*v));
+                let max = world
+                    .iter()
+                    .fold(Vec2::splat(f32::NEG_INFINITY), |acc, v| acc.max(*v));
+                (min, max)
+            }
         }
     }
 }
@@ -204,12 +644,46 @@ impl Geometry {
             Geometry::Finite(shape) => shape.draw(),
         }
     }
+
+    // `None` for infinite geometry, which has no bounding box and is
+    // excluded from the broad-phase sweep.
+    fn aabb(&self) -> Option<(Vec2, Vec2)> {
+        match self {
+            Geometry::Infinite(_) => None,
+            Geometry::Finite(shape) => Some(shape.aabb(Vec2::ZERO, 0.)),
+        }
+    }
+}
+
+type BodyId = usize;
+type GeometryRef = usize;
+
+// Emitted whenever a collision resolves, whether caught by the discrete
+// per-frame test in `State::collide` or by `Body::advance`'s continuous
+// sweep, so calling code can react (play a sound, award points, despawn a
+// body) without reaching into the solver.
+//
+// Not every field is consumed yet (`main` only wires up `on_collision`,
+// which reads `impulse`); the rest is here for the gameplay hooks this
+// unlocks.
+#[allow(dead_code)]
+struct CollisionEvent {
+    a: BodyId,
+    b: GeometryRef,
+    point: Vec2,
+    normal: Vec2,
+    depth: f32,
+    impulse: f32,
 }
 
 // TODO: use Geometry
 struct State {
     geometry: Vec<Geometry>,
     bodies: Vec<Body>,
+    events: Vec<CollisionEvent>,
+    // Bodies involved in an event this rule accepts are removed at the end
+    // of `step`.
+    despawn_rule: Option<fn(&CollisionEvent) -> bool>,
 }
 
 impl State {
@@ -219,20 +693,145 @@ impl State {
         }
     }
 
+    // Broad phase: sort-and-sweep the x-intervals of every body's and
+    // finite geometry's AABB, only running the exact narrow-phase test on
+    // candidate pairs whose boxes actually overlap. Infinite geometry (e.g.
+    // `Line`) has no AABB, so it's excluded from the sweep and tested
+    // against every body directly.
     fn collide(&mut self) {
-        for b in &mut self.bodies {
-            for g in &self.geometry {
-                b.collide(g);
+        // `true` for a body (index into `self.bodies`), `false` for a
+        // finite geometry item (index into `self.geometry`).
+        let mut objs: Vec<(usize, bool, Vec2, Vec2)> = self
+            .bodies
+            .iter()
+            .enumerate()
+            .map(|(i, b)| {
+                let (min, max) = b.shape.aabb(b.pos, b.angle);
+                (i, true, min, max)
+            })
+            .collect();
+
+        objs.extend(
+            self.geometry
+                .iter()
+                .enumerate()
+                .filter_map(|(j, g)| g.aabb().map(|(min, max)| (j, false, min, max))),
+        );
+
+        objs.sort_by(|a, b| a.2.x.partial_cmp(&b.2.x).unwrap());
+
+        let mut active: Vec<usize> = Vec::new();
+        for k in 0..objs.len() {
+            let (idx, is_body, min, max) = objs[k];
+
+            active.retain(|&a| objs[a].3.x >= min.x);
+
+            for &a in &active {
+                let (aidx, a_is_body, amin, amax) = objs[a];
+                if is_body == a_is_body || amin.y > max.y || min.y > amax.y {
+                    continue;
+                }
+
+                let (bi, gi) = if is_body { (idx, aidx) } else { (aidx, idx) };
+                if let Some((point, normal, depth, impulse)) =
+                    self.bodies[bi].collide(&self.geometry[gi])
+                {
+                    self.events.push(CollisionEvent {
+                        a: bi,
+                        b: gi,
+                        point,
+                        normal,
+                        depth,
+                        impulse,
+                    });
+                }
+            }
+
+            active.push(k);
+        }
+
+        for (bi, b) in self.bodies.iter_mut().enumerate() {
+            for (gi, g) in self.geometry.iter().enumerate() {
+                if matches!(g, Geometry::Infinite(_)) {
+                    if let Some((point, normal, depth, impulse)) = b.collide(g) {
+                        self.events.push(CollisionEvent {
+                            a: bi,
+                            b: gi,
+                            point,
+                            normal,
+                            depth,
+                            impulse,
+                        });
+                    }
+                }
             }
         }
     }
 
-    fn step(&mut self, dt: f32) {
-        for b in &mut self.bodies {
-            b.step(dt);
+    // Registers a rule run against every `CollisionEvent`; a body involved
+    // in an event the rule accepts is removed at the end of `step`.
+    fn on_collision(&mut self, rule: fn(&CollisionEvent) -> bool) {
+        self.despawn_rule = Some(rule);
+    }
+
+    // Removes bodies marked by `despawn_rule`, then fixes up `self.events`
+    // so `CollisionEvent::a` still refers to the right body (or is dropped,
+    // if that body was the one despawned) once `step` hands the events back
+    // to the caller.
+    fn despawn_marked(&mut self) {
+        let Some(rule) = self.despawn_rule else {
+            return;
+        };
+
+        let mut marked: Vec<BodyId> = self.events.iter().filter(|e| rule(e)).map(|e| e.a).collect();
+        marked.sort_unstable();
+        marked.dedup();
+
+        if marked.is_empty() {
+            return;
+        }
+
+        let mut new_index = vec![None; self.bodies.len()];
+        let mut next = 0;
+        for (old, slot) in new_index.iter_mut().enumerate() {
+            if marked.binary_search(&old).is_err() {
+                *slot = Some(next);
+                next += 1;
+            }
+        }
+
+        for &i in marked.iter().rev() {
+            self.bodies.remove(i);
+        }
+
+        self.events.retain_mut(|e| match new_index[e.a] {
+            Some(new) => {
+                e.a = new;
+                true
+            }
+            None => false,
+        });
+    }
+
+    fn step(&mut self, dt: f32) -> Vec<CollisionEvent> {
+        for (bi, b) in self.bodies.iter_mut().enumerate() {
+            b.integrate(dt);
+            if let Some((gi, point, normal, depth, impulse)) = b.advance(&self.geometry, dt) {
+                self.events.push(CollisionEvent {
+                    a: bi,
+                    b: gi,
+                    point,
+                    normal,
+                    depth,
+                    impulse,
+                });
+            }
         }
 
         self.collide();
+        self.despawn_marked();
+
+        std::mem::take(&mut self.events)
     }
 
     fn draw(&self) {
@@ -253,7 +852,16 @@ async fn main() {
         pos: Vec2::ZERO,
         r: 20.,
     };
-    let body = Body::new(1., 1000., FiniteShape::Circle(circ), pos, 1.);
+    let body = Body::new(1., 1000., FiniteShape::Circle(circ), 0.8, pos, 1.);
+    let cube = FiniteShape::Polygon {
+        verts: vec![
+            (-20., -20.).into(),
+            (20., -20.).into(),
+            (20., 20.).into(),
+            (-20., 20.).into(),
+        ],
+    };
+    let cube_body = Body::new(1., 1000., cube, 0.3, (300., 100.).into(), 0.);
     let line = Line {
         a: 0.,
         b: -1.,
@@ -273,16 +881,25 @@ async fn main() {
                 r: 150.,
             })),
         ],
-        bodies: vec![body],
+        bodies: vec![body, cube_body],
+        events: Vec::new(),
+        despawn_rule: None,
     };
 
+    // Example gameplay hook: a hard enough hit despawns the body.
+    state.on_collision(|e| e.impulse > 400.);
+
     loop {
         state.draw();
 
         let dt = get_frame_time();
 
         state.set_gravity((0., 200.).into());
-        state.step(dt);
+        let events = state.step(dt);
+        for _event in &events {
+            // gameplay code (sound effects, scoring, ...) would react to
+            // individual collisions here
+        }
 
         next_frame().await;
     }